@@ -1,5 +1,8 @@
 use crate::{noise_fns::NoiseFn, MultiFractal, Seedable};
 use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, VecDeque};
+use std::sync::RwLock;
+use std::thread::{self, ThreadId};
 
 /// Noise function that caches the last output value generated by the source
 /// function.
@@ -14,17 +17,23 @@ use std::cell::{Cell, RefCell};
 /// multiple noise functions. If a source function is not cached, the source
 /// function will redundantly calculate the same output value once for each
 /// noise function in which it is included.
+///
+/// `Cache` is generic over the coordinate scalar type, so it works equally
+/// well beneath a source that samples in `f64` or, for callers that want to
+/// halve their point-array memory, beneath one that samples in `f32`. The
+/// cached output value itself is always `f64`, matching `NoiseFn::get`'s
+/// return type.
 #[derive(Clone, Debug)]
-pub struct Cache<Source> {
+pub struct Cache<Source, Scalar = f64> {
     /// Outputs the value to be cached.
     pub source: Source,
 
     value: Cell<Option<f64>>,
 
-    point: RefCell<Vec<f64>>,
+    point: RefCell<Vec<Scalar>>,
 }
 
-impl<Source> Cache<Source> {
+impl<Source, Scalar> Cache<Source, Scalar> {
     pub fn new(source: Source) -> Self {
         Cache {
             source,
@@ -34,11 +43,12 @@ impl<Source> Cache<Source> {
     }
 }
 
-impl<Source, const N: usize> NoiseFn<N> for Cache<Source>
+impl<Source, Scalar, const N: usize> NoiseFn<Scalar, N> for Cache<Source, Scalar>
 where
-    Source: NoiseFn<N>,
+    Source: NoiseFn<Scalar, N>,
+    Scalar: Copy + PartialEq,
 {
-    fn get(&self, point: [f64; N]) -> f64 {
+    fn get(&self, point: [Scalar; N]) -> f64 {
         match self.value.get() {
             Some(value) if quick_eq(&*self.point.borrow(), &point) => value,
             Some(_) | None => {
@@ -54,7 +64,7 @@ where
         }
     }
 }
-impl<T> Seedable for Cache<T>
+impl<T, Scalar> Seedable for Cache<T, Scalar>
 where
     T: Seedable,
 {
@@ -79,7 +89,7 @@ where
     }
 }
 
-impl<T> MultiFractal for Cache<T>
+impl<T, Scalar> MultiFractal for Cache<T, Scalar>
 where
     T: MultiFractal,
 {
@@ -112,8 +122,514 @@ where
     }
 }
 
-fn quick_eq(a: &[f64], b: &[f64]) -> bool {
+fn quick_eq<Scalar: PartialEq>(a: &[Scalar], b: &[Scalar]) -> bool {
     assert_eq!(a.len(), b.len());
 
     a.iter().eq(b)
 }
+
+/// Blanket impl so a borrowed noise function can be used directly as a
+/// source, e.g. to share one `SyncCache` across several
+/// combinators without cloning it.
+impl<M, Scalar, const N: usize> NoiseFn<Scalar, N> for &M
+where
+    M: NoiseFn<Scalar, N>,
+{
+    fn get(&self, point: [Scalar; N]) -> f64 {
+        M::get(self, point)
+    }
+}
+
+/// Blanket impl so a boxed noise function can be used directly as a source.
+impl<M, Scalar, const N: usize> NoiseFn<Scalar, N> for Box<M>
+where
+    M: NoiseFn<Scalar, N>,
+{
+    fn get(&self, point: [Scalar; N]) -> f64 {
+        M::get(self, point)
+    }
+}
+
+/// Upcasts an `f32` point to the `f64` points most generators work in
+/// natively, so a combinator stack built for `f32` callers can still defer to
+/// an `f64`-only source without every caller hand-rolling the conversion.
+pub fn upcast_point<const N: usize>(point: [f32; N]) -> [f64; N] {
+    point.map(f64::from)
+}
+
+/// Adapts an `f64`-only source so it can sit beneath an `f32`-keyed
+/// combinator stack, upcasting each point once per call via [`upcast_point`].
+#[derive(Clone, Debug)]
+pub struct UpcastF32<Source> {
+    /// Outputs a value from the upcast `f64` point.
+    pub source: Source,
+}
+
+impl<Source> UpcastF32<Source> {
+    pub fn new(source: Source) -> Self {
+        UpcastF32 { source }
+    }
+}
+
+impl<Source, const N: usize> NoiseFn<f32, N> for UpcastF32<Source>
+where
+    Source: NoiseFn<f64, N>,
+{
+    fn get(&self, point: [f32; N]) -> f64 {
+        self.source.get(upcast_point(point))
+    }
+}
+
+/// Shared fixture for the `cache` test modules below: a trivial `NoiseFn`
+/// that sums its point's coordinates, so tests can assert on its output
+/// without depending on a real generator.
+#[cfg(test)]
+mod test_fixtures {
+    use super::NoiseFn;
+
+    pub struct AxisSumSource;
+
+    impl NoiseFn<f64, 2> for AxisSumSource {
+        fn get(&self, point: [f64; 2]) -> f64 {
+            point[0] + point[1]
+        }
+    }
+}
+
+#[cfg(test)]
+mod scalar_generic_tests {
+    use super::test_fixtures::AxisSumSource;
+    use super::*;
+
+    #[test]
+    fn cache_works_over_f32_points_via_upcast_f32() {
+        let cache: Cache<_, f32> = Cache::new(UpcastF32::new(AxisSumSource));
+
+        let value = NoiseFn::<f32, 2>::get(&cache, [1.0_f32, 2.0_f32]);
+
+        assert_eq!(value, 3.0);
+    }
+
+    #[test]
+    fn blanket_ref_impl_forwards_to_the_inner_source() {
+        fn get_through<S: NoiseFn<f64, 2>>(source: S, point: [f64; 2]) -> f64 {
+            source.get(point)
+        }
+
+        let source = AxisSumSource;
+
+        assert_eq!(get_through(&source, [1.0, 2.0]), 3.0);
+    }
+
+    #[test]
+    fn upcast_f32_defers_to_an_f64_only_source() {
+        let adapter = UpcastF32::new(AxisSumSource);
+
+        let value = NoiseFn::<f32, 2>::get(&adapter, [1.5_f32, 2.5_f32]);
+
+        assert_eq!(value, 4.0);
+    }
+}
+
+/// Node stored per cached point, tracking how many times it has been looked
+/// up (the inserting miss counts as the first lookup) so
+/// the least-frequently-used entry can be found on eviction.
+#[derive(Clone, Debug)]
+struct LfuNode {
+    value: f64,
+    freq: usize,
+}
+
+/// Noise function that caches up to `capacity` of the most recently useful
+/// output values generated by the source function, evicting the
+/// least-frequently-used entry once the cache is full. Unlike `Cache`, which
+/// only remembers a single point, `LfuCache` retains several.
+///
+/// Points are keyed on the bit patterns of their coordinates (`f64::to_bits`),
+/// since `f64` does not implement `Eq`/`Hash`.
+///
+/// Each frequency is tracked as a `VecDeque` of keys rather than a pointer-
+/// based intrusive list, so moving a key to its next frequency on a hit
+/// (`touch`) is a linear scan of that frequency's bucket rather than an O(1)
+/// unlink/relink. Since every bucket holds at most `capacity` keys, this
+/// keeps `touch`/`insert` at O(capacity) per call, not O(1) — an acceptable
+/// trade for the small capacities this cache targets, in exchange for not
+/// hand-rolling an intrusive linked list.
+#[derive(Clone, Debug)]
+pub struct LfuCache<Source> {
+    /// Outputs the value to be cached.
+    pub source: Source,
+
+    capacity: usize,
+
+    nodes: RefCell<HashMap<Vec<u64>, LfuNode>>,
+
+    /// Points at each frequency count, ordered from least to most recently
+    /// inserted at that frequency. Eviction pops the front of the list at
+    /// `min_freq`.
+    freq_lists: RefCell<HashMap<usize, VecDeque<Vec<u64>>>>,
+
+    min_freq: Cell<usize>,
+}
+
+impl<Source> LfuCache<Source> {
+    /// Constructs a new `LfuCache` that retains up to `capacity` points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn with_capacity(source: Source, capacity: usize) -> Self {
+        assert!(capacity > 0, "LfuCache capacity must be greater than zero");
+
+        LfuCache {
+            source,
+            capacity,
+            nodes: RefCell::new(HashMap::new()),
+            freq_lists: RefCell::new(HashMap::new()),
+            min_freq: Cell::new(0),
+        }
+    }
+
+    fn key<const N: usize>(point: &[f64; N]) -> Vec<u64> {
+        point.iter().map(|coord| coord.to_bits()).collect()
+    }
+
+    /// Moves `key` from its current frequency list to the next one up,
+    /// bumping `min_freq` if that was the only entry at the old frequency.
+    fn touch(&self, key: &[u64]) -> f64 {
+        let mut nodes = self.nodes.borrow_mut();
+        let mut freq_lists = self.freq_lists.borrow_mut();
+
+        let node = nodes.get_mut(key).expect("key must be present");
+        let old_freq = node.freq;
+        node.freq += 1;
+        let value = node.value;
+
+        if let Some(list) = freq_lists.get_mut(&old_freq) {
+            list.retain(|k| k != key);
+        }
+        if freq_lists.get(&old_freq).is_some_and(VecDeque::is_empty) {
+            freq_lists.remove(&old_freq);
+            if self.min_freq.get() == old_freq {
+                self.min_freq.set(old_freq + 1);
+            }
+        }
+
+        freq_lists
+            .entry(old_freq + 1)
+            .or_default()
+            .push_back(key.to_vec());
+
+        value
+    }
+
+    /// Evicts the least-frequently-used entry, then inserts `key` at
+    /// frequency 1.
+    fn insert(&self, key: Vec<u64>, value: f64) {
+        let mut nodes = self.nodes.borrow_mut();
+        let mut freq_lists = self.freq_lists.borrow_mut();
+
+        if nodes.len() >= self.capacity {
+            let min_freq = self.min_freq.get();
+            let evicted = freq_lists
+                .get_mut(&min_freq)
+                .and_then(VecDeque::pop_front);
+
+            if let Some(evicted) = evicted {
+                nodes.remove(&evicted);
+            }
+            if freq_lists.get(&min_freq).is_some_and(VecDeque::is_empty) {
+                freq_lists.remove(&min_freq);
+            }
+        }
+
+        nodes.insert(key.clone(), LfuNode { value, freq: 1 });
+        freq_lists.entry(1).or_default().push_back(key);
+        self.min_freq.set(1);
+    }
+}
+
+impl<Source, const N: usize> NoiseFn<f64, N> for LfuCache<Source>
+where
+    Source: NoiseFn<f64, N>,
+{
+    fn get(&self, point: [f64; N]) -> f64 {
+        let key = Self::key(&point);
+
+        if self.nodes.borrow().contains_key(&key) {
+            return self.touch(&key);
+        }
+
+        let value = self.source.get(point);
+        self.insert(key, value);
+        value
+    }
+}
+
+impl<T> Seedable for LfuCache<T>
+where
+    T: Seedable,
+{
+    fn new(seed: u32) -> Self {
+        Self::with_capacity(T::new(seed), DEFAULT_LFU_CAPACITY)
+    }
+
+    fn set_seed(self, seed: u32) -> Self {
+        Self {
+            source: self.source.set_seed(seed),
+            capacity: self.capacity,
+            nodes: self.nodes,
+            freq_lists: self.freq_lists,
+            min_freq: self.min_freq,
+        }
+    }
+
+    fn seed(&self) -> u32 {
+        self.source.seed()
+    }
+}
+
+impl<T> MultiFractal for LfuCache<T>
+where
+    T: MultiFractal,
+{
+    fn set_octaves(self, octaves: usize) -> Self {
+        Self {
+            source: self.source.set_octaves(octaves),
+            ..self
+        }
+    }
+
+    fn set_frequency(self, frequency: f64) -> Self {
+        Self {
+            source: self.source.set_frequency(frequency),
+            ..self
+        }
+    }
+
+    fn set_lacunarity(self, lacunarity: f64) -> Self {
+        Self {
+            source: self.source.set_lacunarity(lacunarity),
+            ..self
+        }
+    }
+
+    fn set_persistence(self, persistence: f64) -> Self {
+        Self {
+            source: self.source.set_persistence(persistence),
+            ..self
+        }
+    }
+}
+
+/// Default capacity used by `<LfuCache<T> as Seedable>::new`, which has no
+/// other way to learn how many points the caller wants retained.
+const DEFAULT_LFU_CAPACITY: usize = 4;
+
+#[cfg(test)]
+mod lfu_cache_tests {
+    use super::test_fixtures::AxisSumSource;
+    use super::*;
+
+    #[test]
+    fn stays_within_capacity() {
+        let cache = LfuCache::with_capacity(AxisSumSource, 2);
+
+        for i in 0..10 {
+            cache.get([i as f64, 0.0]);
+        }
+
+        assert_eq!(cache.nodes.borrow().len(), 2);
+    }
+
+    #[test]
+    fn evicts_least_frequently_used_entry() {
+        let cache = LfuCache::with_capacity(AxisSumSource, 2);
+
+        cache.get([1.0, 0.0]);
+        cache.get([2.0, 0.0]);
+        cache.get([1.0, 0.0]);
+        cache.get([3.0, 0.0]);
+
+        let nodes = cache.nodes.borrow();
+        assert!(nodes.contains_key(&LfuCache::<AxisSumSource>::key(&[1.0, 0.0])));
+        assert!(!nodes.contains_key(&LfuCache::<AxisSumSource>::key(&[2.0, 0.0])));
+        assert!(nodes.contains_key(&LfuCache::<AxisSumSource>::key(&[3.0, 0.0])));
+    }
+
+    #[test]
+    fn does_not_leak_empty_frequency_buckets() {
+        let cache = LfuCache::with_capacity(AxisSumSource, 1);
+
+        for _ in 0..50 {
+            cache.get([1.0, 0.0]);
+        }
+
+        assert_eq!(cache.freq_lists.borrow().len(), 1);
+    }
+}
+
+/// Noise function that caches the last output value generated by the source
+/// function, the same way `Cache` does, but is safe to share across threads.
+///
+/// `Cache` stores its cached point and value behind `Cell`/`RefCell`, so it is
+/// neither `Sync` nor safe to call concurrently. `SyncCache` keeps one cache
+/// slot per thread, keyed by `thread::current().id()`, behind a single
+/// `RwLock`. Concurrent calls from different threads each hit their own
+/// slot, so the single-value fast path of `Cache` is preserved per thread
+/// without the contention of sharing one slot across the whole pool.
+///
+/// `SyncCache<Source>` is `Send + Sync` whenever `Source` is.
+///
+/// A shard is never reclaimed once its thread has exited, so `SyncCache` only
+/// stays bounded in memory when it is shared across a fixed-size pool (e.g. a
+/// rayon thread pool). Sharing one `SyncCache` across a workload that keeps
+/// spawning new, short-lived threads will leak one shard per thread for the
+/// life of the cache.
+#[derive(Debug)]
+pub struct SyncCache<Source> {
+    /// Outputs the value to be cached.
+    pub source: Source,
+
+    shards: RwLock<HashMap<ThreadId, (Vec<f64>, f64)>>,
+}
+
+impl<Source> SyncCache<Source> {
+    pub fn new(source: Source) -> Self {
+        SyncCache {
+            source,
+            shards: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl<Source> Clone for SyncCache<Source>
+where
+    Source: Clone,
+{
+    fn clone(&self) -> Self {
+        // Per-thread cache slots aren't meaningful to copy onto a new value,
+        // so a clone just starts with an empty set of shards.
+        SyncCache::new(self.source.clone())
+    }
+}
+
+impl<Source, const N: usize> NoiseFn<f64, N> for SyncCache<Source>
+where
+    Source: NoiseFn<f64, N>,
+{
+    fn get(&self, point: [f64; N]) -> f64 {
+        let this_thread = thread::current().id();
+
+        let cached = self
+            .shards
+            .read()
+            .expect("cache lock was poisoned by a panicking thread")
+            .get(&this_thread)
+            .filter(|(cached_point, _)| quick_eq(cached_point, &point))
+            .map(|(_, value)| *value);
+
+        if let Some(value) = cached {
+            return value;
+        }
+
+        let value = self.source.get(point);
+
+        self.shards
+            .write()
+            .expect("cache lock was poisoned by a panicking thread")
+            .insert(this_thread, (point.to_vec(), value));
+
+        value
+    }
+}
+
+impl<T> Seedable for SyncCache<T>
+where
+    T: Seedable,
+{
+    fn new(seed: u32) -> Self {
+        SyncCache::new(T::new(seed))
+    }
+
+    fn set_seed(self, seed: u32) -> Self {
+        Self {
+            source: self.source.set_seed(seed),
+            shards: self.shards,
+        }
+    }
+
+    fn seed(&self) -> u32 {
+        self.source.seed()
+    }
+}
+
+impl<T> MultiFractal for SyncCache<T>
+where
+    T: MultiFractal,
+{
+    fn set_octaves(self, octaves: usize) -> Self {
+        Self {
+            source: self.source.set_octaves(octaves),
+            ..self
+        }
+    }
+
+    fn set_frequency(self, frequency: f64) -> Self {
+        Self {
+            source: self.source.set_frequency(frequency),
+            ..self
+        }
+    }
+
+    fn set_lacunarity(self, lacunarity: f64) -> Self {
+        Self {
+            source: self.source.set_lacunarity(lacunarity),
+            ..self
+        }
+    }
+
+    fn set_persistence(self, persistence: f64) -> Self {
+        Self {
+            source: self.source.set_persistence(persistence),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod sync_cache_tests {
+    use super::test_fixtures::AxisSumSource;
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn returns_correct_values_across_threads() {
+        let cache = Arc::new(SyncCache::new(AxisSumSource));
+
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let cache = Arc::clone(&cache);
+                thread::spawn(move || {
+                    for _ in 0..100 {
+                        assert_eq!(cache.get([i as f64, 1.0]), i as f64 + 1.0);
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(cache.shards.read().unwrap().len(), 8);
+    }
+
+    #[test]
+    fn sync_cache_can_be_shared_via_blanket_ref_impl() {
+        let cache = SyncCache::new(AxisSumSource);
+        let wrapped = Cache::new(&cache);
+
+        assert_eq!(NoiseFn::<f64, 2>::get(&wrapped, [1.0, 2.0]), 3.0);
+    }
+}
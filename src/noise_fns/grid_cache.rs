@@ -0,0 +1,208 @@
+use crate::{noise_fns::NoiseFn, MultiFractal, Seedable};
+
+/// Wrapper that evaluates its source noise function once per cell of a fixed
+/// region and caches the results in a flat buffer, for bulk-filling a
+/// heightmap or texture without re-walking the whole combinator tree per
+/// texel. `GridCache` does not itself implement `NoiseFn`; its results are
+/// read through `get_values`.
+///
+/// Sampling a 2D (or higher-dimensional) region one point at a time with
+/// `NoiseFn::get` throws away all locality: every texel re-walks the entire
+/// source tree from scratch. `GridCache` instead samples the region once,
+/// up front, into a `values` buffer that callers can read directly with
+/// `get_values`. If the source is reseeded or its octave count changes, the
+/// buffer is marked `dirty` rather than recomputed immediately, so a caller
+/// can decide when it's worth paying for a re-sample (for example, once per
+/// frame rather than once per `set_seed` call).
+#[derive(Clone, Debug)]
+pub struct GridCache<Source, const N: usize> {
+    /// Outputs the values to be cached.
+    pub source: Source,
+
+    values: Box<[f64]>,
+
+    dirty: bool,
+
+    origin: [f64; N],
+    step: [f64; N],
+    dims: [usize; N],
+}
+
+impl<Source, const N: usize> GridCache<Source, N>
+where
+    Source: NoiseFn<f64, N>,
+{
+    /// Constructs a `GridCache` covering a region of `dims` cells per axis,
+    /// starting at `origin` and advancing by `step` per cell, then samples
+    /// `source` once for every cell in that region.
+    pub fn new(source: Source, origin: [f64; N], step: [f64; N], dims: [usize; N]) -> Self {
+        let len = dims.iter().product();
+
+        let mut cache = GridCache {
+            source,
+            values: vec![0.0; len].into_boxed_slice(),
+            dirty: true,
+            origin,
+            step,
+            dims,
+        };
+
+        cache.sample_region();
+
+        cache
+    }
+
+    /// Evaluates `source` once per cell of the region and stores the results,
+    /// clearing the `dirty` flag.
+    pub fn sample_region(&mut self) {
+        for (index, slot) in self.values.iter_mut().enumerate() {
+            let mut remainder = index;
+            let mut point = [0.0; N];
+
+            for (axis, coordinate) in point.iter_mut().enumerate() {
+                let cell = remainder % self.dims[axis];
+                remainder /= self.dims[axis];
+
+                *coordinate = self.origin[axis] + cell as f64 * self.step[axis];
+            }
+
+            *slot = self.source.get(point);
+        }
+
+        self.dirty = false;
+    }
+
+    /// Returns the cached values, in row-major order with the first axis
+    /// changing fastest.
+    pub fn get_values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Clears the `dirty` flag without resampling, for callers that have
+    /// handled the stale buffer some other way (e.g. accepted it as-is).
+    pub fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    /// Returns `true` if `source` has been reseeded or reconfigured since the
+    /// buffer was last sampled, meaning `get_values` is stale and the buffer
+    /// must be re-uploaded after a call to `sample_region`.
+    pub fn dirty(&self) -> bool {
+        self.dirty
+    }
+}
+
+impl<Source, const N: usize> Seedable for GridCache<Source, N>
+where
+    Source: Seedable + NoiseFn<f64, N>,
+{
+    /// Constructs a `GridCache` covering a single default cell at the origin.
+    ///
+    /// `Seedable::new` has no way to receive region geometry, so this impl
+    /// exists only so `GridCache` satisfies a `T: Seedable` bound elsewhere
+    /// in the combinator stack. Prefer `GridCache::new` directly when you
+    /// have real origin/step/dims to sample.
+    fn new(seed: u32) -> Self {
+        GridCache::new(Source::new(seed), [0.0; N], [1.0; N], [1; N])
+    }
+
+    /// Reseeds the source and marks the buffer dirty; does not resample.
+    fn set_seed(mut self, seed: u32) -> Self {
+        self.source = self.source.set_seed(seed);
+        self.dirty = true;
+        self
+    }
+
+    fn seed(&self) -> u32 {
+        self.source.seed()
+    }
+}
+
+impl<Source, const N: usize> MultiFractal for GridCache<Source, N>
+where
+    Source: MultiFractal,
+{
+    /// Changes the source's octave count and marks the buffer dirty; does
+    /// not resample.
+    fn set_octaves(mut self, octaves: usize) -> Self {
+        self.source = self.source.set_octaves(octaves);
+        self.dirty = true;
+        self
+    }
+
+    fn set_frequency(mut self, frequency: f64) -> Self {
+        self.source = self.source.set_frequency(frequency);
+        self.dirty = true;
+        self
+    }
+
+    fn set_lacunarity(mut self, lacunarity: f64) -> Self {
+        self.source = self.source.set_lacunarity(lacunarity);
+        self.dirty = true;
+        self
+    }
+
+    fn set_persistence(mut self, persistence: f64) -> Self {
+        self.source = self.source.set_persistence(persistence);
+        self.dirty = true;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct AxisSumSource;
+
+    impl NoiseFn<f64, 2> for AxisSumSource {
+        fn get(&self, point: [f64; 2]) -> f64 {
+            point[0] * 10.0 + point[1]
+        }
+    }
+
+    impl Seedable for AxisSumSource {
+        fn new(_seed: u32) -> Self {
+            AxisSumSource
+        }
+
+        fn set_seed(self, _seed: u32) -> Self {
+            self
+        }
+
+        fn seed(&self) -> u32 {
+            0
+        }
+    }
+
+    #[test]
+    fn sample_region_matches_direct_calls() {
+        let origin = [0.0, 0.0];
+        let step = [1.0, 2.0];
+        let dims = [3, 4];
+
+        let cache = GridCache::new(AxisSumSource, origin, step, dims);
+
+        for y in 0..dims[1] {
+            for x in 0..dims[0] {
+                let point = [origin[0] + x as f64 * step[0], origin[1] + y as f64 * step[1]];
+                let expected = AxisSumSource.get(point);
+                let index = x + y * dims[0];
+
+                assert_eq!(cache.get_values()[index], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn set_seed_marks_dirty_without_resampling() {
+        let cache = GridCache::new(AxisSumSource, [0.0, 0.0], [1.0, 1.0], [2, 2]);
+        assert!(!cache.dirty());
+
+        let mut cache = cache.set_seed(42);
+        assert!(cache.dirty());
+
+        cache.sample_region();
+        assert!(!cache.dirty());
+    }
+}